@@ -42,12 +42,16 @@ impl TryFrom<&str> for ID {
     }
 }
 
+/// The ordering is lexicographic on `(peer, counter)`.
+///
+/// NOTE: It's **not** the causal order. Use the DAG to compare the versions causally.
 impl PartialOrd for ID {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
         Some(self.cmp(other))
     }
 }
 
+/// The ordering is lexicographic on `(peer, counter)`, see [PartialOrd] impl of [ID].
 impl Ord for ID {
     fn cmp(&self, other: &Self) -> std::cmp::Ordering {
         match self.peer.cmp(&other.peer) {
@@ -109,13 +113,19 @@ impl ID {
     }
 
     #[inline]
-    #[allow(dead_code)]
     pub(crate) fn is_connected_id(&self, other: &Self, self_len: usize) -> bool {
         self.peer == other.peer && self.counter + self_len as Counter == other.counter
     }
 
+    /// Whether `other` is the next id of `self` from the same peer
     #[inline]
-    pub fn inc(&self, inc: i32) -> Self {
+    pub fn is_connected(&self, other: ID) -> bool {
+        self.is_connected_id(&other, 1)
+    }
+
+    /// Advance the counter by `inc`
+    #[inline]
+    pub fn inc(&self, inc: Counter) -> Self {
         ID {
             peer: self.peer,
             counter: self.counter + inc,
@@ -145,3 +155,20 @@ impl RangeBounds<ID> for (ID, ID) {
         std::ops::Bound::Excluded(&self.1)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn id_order_and_connection() {
+        let a = ID::new(1, 5);
+        assert!(a < ID::new(1, 6));
+        assert!(a < ID::new(2, 0));
+        assert!(ID::new(0, 100) < a);
+        assert_eq!(a.inc(3), ID::new(1, 8));
+        assert!(a.is_connected(ID::new(1, 6)));
+        assert!(!a.is_connected(ID::new(1, 7)));
+        assert!(!a.is_connected(ID::new(2, 6)));
+    }
+}
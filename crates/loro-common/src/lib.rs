@@ -69,22 +69,13 @@ impl AsULE for ContainerType {
     type ULE = u8;
 
     fn to_unaligned(self) -> Self::ULE {
-        match self {
-            ContainerType::Map => 1,
-            ContainerType::List => 2,
-            ContainerType::Text => 3,
-            ContainerType::Tree => 4,
-        }
+        self.to_u8()
     }
 
+    /// It panics on unknown tags, so it should only be used on trusted input.
+    /// See [ContainerType::try_from_u8].
     fn from_unaligned(unaligned: Self::ULE) -> Self {
-        match unaligned {
-            1 => ContainerType::Map,
-            2 => ContainerType::List,
-            3 => ContainerType::Text,
-            4 => ContainerType::Tree,
-            _ => unreachable!(),
-        }
+        ContainerType::from_u8(unaligned)
     }
 }
 
@@ -107,13 +98,26 @@ impl ContainerType {
         }
     }
 
+    /// Trusted input only. It panics if `v` is not a valid tag.
+    ///
+    /// Use [ContainerType::try_from_u8] when decoding untrusted data.
     pub fn from_u8(v: u8) -> Self {
+        Self::try_from_u8(v).unwrap()
+    }
+
+    /// # Err
+    ///
+    /// - Return Err(LoroError::DecodeError) when `v` is not a valid tag,
+    ///   e.g. the data is corrupted or encoded by a newer version
+    pub fn try_from_u8(v: u8) -> LoroResult<Self> {
         match v {
-            1 => ContainerType::Map,
-            2 => ContainerType::List,
-            3 => ContainerType::Text,
-            4 => ContainerType::Tree,
-            _ => unreachable!(),
+            1 => Ok(ContainerType::Map),
+            2 => Ok(ContainerType::List),
+            3 => Ok(ContainerType::Text),
+            4 => Ok(ContainerType::Tree),
+            _ => Err(LoroError::DecodeError(
+                format!("Unknown container type tag {}", v).into_boxed_str(),
+            )),
         }
     }
 }
@@ -372,7 +376,28 @@ pub mod wasm {
 
 #[cfg(test)]
 mod test {
-    use crate::ContainerID;
+    use crate::{ContainerID, ContainerType, LoroError};
+
+    #[test]
+    fn container_type_try_from_u8() {
+        for ty in [
+            ContainerType::Map,
+            ContainerType::List,
+            ContainerType::Text,
+            ContainerType::Tree,
+        ] {
+            assert_eq!(ContainerType::try_from_u8(ty.to_u8()).unwrap(), ty);
+        }
+
+        assert!(matches!(
+            ContainerType::try_from_u8(0),
+            Err(LoroError::DecodeError(_))
+        ));
+        assert!(matches!(
+            ContainerType::try_from_u8(99),
+            Err(LoroError::DecodeError(_))
+        ));
+    }
 
     #[test]
     fn test_container_id_convert_to_and_from_str() {
@@ -122,6 +122,15 @@ impl CounterSpan {
         }
     }
 
+    /// Iterate over the counters in the span, from `start` to `end` (exclusive).
+    ///
+    /// If the span is reversed, the counters are yielded in descending order.
+    pub fn iter(&self) -> impl Iterator<Item = Counter> {
+        let start = self.start;
+        let direction = self.direction();
+        (0..self.content_len() as Counter).map(move |i| start + i * direction)
+    }
+
     #[inline]
     pub fn contains(&self, v: Counter) -> bool {
         if self.start < self.end {
@@ -281,6 +290,30 @@ impl IdSpan {
         out.insert(self.client_id, self.counter);
         out
     }
+
+    /// Iterate over the ids in the span. See [CounterSpan::iter]
+    pub fn iter(&self) -> impl Iterator<Item = ID> {
+        let peer = self.client_id;
+        self.counter
+            .iter()
+            .map(move |counter| ID::new(peer, counter))
+    }
+}
+
+pub trait IdSpanVectorExt {
+    /// Iterate over the ids in all the spans. See [CounterSpan::iter]
+    ///
+    /// The order of the peers is unspecified.
+    fn iter_ids(&self) -> Box<dyn Iterator<Item = ID> + '_>;
+}
+
+impl IdSpanVectorExt for IdSpanVector {
+    fn iter_ids(&self) -> Box<dyn Iterator<Item = ID> + '_> {
+        Box::new(self.iter().flat_map(|(peer, counter)| {
+            let peer = *peer;
+            counter.iter().map(move |counter| ID::new(peer, counter))
+        }))
+    }
 }
 
 impl HasLength for IdSpan {
@@ -446,6 +479,29 @@ mod test_id_span {
         };
     }
 
+    #[test]
+    fn iter_ids() {
+        let span = IdSpan::new(1, 2, 5);
+        assert_eq!(
+            span.iter().collect::<Vec<_>>(),
+            vec![ID::new(1, 2), ID::new(1, 3), ID::new(1, 4)]
+        );
+        let span = IdSpan::new(1, 4, 1);
+        assert_eq!(
+            span.iter().collect::<Vec<_>>(),
+            vec![ID::new(1, 4), ID::new(1, 3), ID::new(1, 2)]
+        );
+        assert_eq!(IdSpan::new(1, 3, 3).iter().count(), 0);
+
+        let mut spans = IdSpanVector::default();
+        spans.insert(1, CounterSpan::new(0, 2));
+        spans.insert(2, CounterSpan::new(5, 6));
+        spans.insert(3, CounterSpan::new(5, 5));
+        let mut ids = spans.iter_ids().collect::<Vec<_>>();
+        ids.sort();
+        assert_eq!(ids, vec![ID::new(1, 0), ID::new(1, 1), ID::new(2, 5)]);
+    }
+
     #[test]
     fn test_id_span_rle_vec() {
         let mut id_span_vec = RleVecWithIndex::new();
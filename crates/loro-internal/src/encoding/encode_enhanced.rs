@@ -499,19 +499,23 @@ pub fn decode_oplog_v2(oplog: &mut OpLog, input: &[u8]) -> Result<(), LoroError>
             let Some(container) = root_containers.get(idx) else {
                 return None;
             };
-            Some(ContainerID::Root {
+            Some(Ok(ContainerID::Root {
                 name: container.name.clone(),
                 container_type: container.type_,
-            })
+            }))
         } else {
             let Some(container) = normal_containers.get(idx - root_containers.len()) else {
                 return None;
             };
-            Some(ContainerID::Normal {
-                peer: peers[container.peer_idx as usize],
-                counter: container.counter,
-                container_type: ContainerType::from_u8(container.type_),
-            })
+            Some(
+                ContainerType::try_from_u8(container.type_).map(|container_type| {
+                    ContainerID::Normal {
+                        peer: peers[container.peer_idx as usize],
+                        counter: container.counter,
+                        container_type,
+                    }
+                }),
+            )
         }
     };
 
@@ -544,6 +548,7 @@ pub fn decode_oplog_v2(oplog: &mut OpLog, input: &[u8]) -> Result<(), LoroError>
                 let Some(container_id) = get_container(container_idx) else {
                     return Err(LoroError::DecodeError("".into()));
                 };
+                let container_id = container_id?;
                 let container_type = container_id.container_type();
                 let content = match container_type {
                     ContainerType::Tree => {
@@ -9,7 +9,7 @@ use std::rc::Rc;
 use std::sync::Mutex;
 
 use fxhash::FxHashMap;
-use rle::{HasLength, RleCollection, RlePush, RleVec, Sliceable};
+use rle::{HasLength, Mergable, RleCollection, RlePush, RleVec, Sliceable};
 use smallvec::SmallVec;
 // use tabled::measurment::Percent;
 
@@ -22,7 +22,7 @@ use crate::encoding::RemoteClientChanges;
 use crate::encoding::{decode_oplog, encode_oplog, EncodeMode};
 use crate::id::{Counter, PeerID, ID};
 use crate::op::{ListSlice, RawOpContent, RemoteOp};
-use crate::span::{HasCounterSpan, HasIdSpan, HasLamportSpan};
+use crate::span::{HasCounterSpan, HasIdSpan, HasLamportSpan, IdSpan};
 use crate::version::{Frontiers, ImVersionVector, VersionVector};
 use crate::LoroError;
 
@@ -294,37 +294,38 @@ impl OpLog {
         change: &Change,
     ) -> EnsureChangeDepsAreAtTheEnd {
         let len = change.content_len();
-        if change.deps_on_self() {
-            // don't need to push new element to dag because it only depends on itself
-            let nodes = self.dag.map.get_mut(&change.id.peer).unwrap();
-            let last = nodes.last_mut().unwrap();
-            assert_eq!(last.peer, change.id.peer);
-            assert_eq!(last.cnt + last.len as Counter, change.id.counter);
-            assert_eq!(last.lamport + last.len as Lamport, change.lamport);
-            last.len = change.id.counter as usize + len - last.cnt as usize;
-            last.has_succ = false;
-        } else {
-            let vv = self.dag.frontiers_to_im_vv(&change.deps);
-            let dag_row = &mut self.dag.map.entry(change.id.peer).or_default();
-            if change.id.counter > 0 {
-                assert_eq!(dag_row.last().unwrap().ctr_end(), change.id.counter);
-            }
-            dag_row.push_rle_element(AppDagNode {
-                vv,
-                peer: change.id.peer,
-                cnt: change.id.counter,
-                lamport: change.lamport,
-                deps: change.deps.clone(),
-                has_succ: false,
-                len,
-            });
-
-            for dep in change.deps.iter() {
-                self.ensure_dep_on_change_end(change.id.peer, *dep);
-                let target = self.dag.get_mut(*dep).unwrap();
-                if target.ctr_last() == dep.counter {
-                    target.has_succ = true;
-                }
+        let mut node = AppDagNode {
+            vv: Default::default(),
+            peer: change.id.peer,
+            cnt: change.id.counter,
+            lamport: change.lamport,
+            deps: change.deps.clone(),
+            has_succ: false,
+            len,
+        };
+        let dag_row = self.dag.map.entry(change.id.peer).or_default();
+        if change.id.counter > 0 {
+            assert_eq!(dag_row.last().unwrap().ctr_end(), change.id.counter);
+        }
+        // It will be merged into the last node if it only depends on the last node,
+        // so there is no need to compute its vv
+        let merged = dag_row.last().is_some_and(|x| x.is_mergable(&node, &()));
+        if merged {
+            dag_row.push_rle_element(node);
+            return EnsureChangeDepsAreAtTheEnd;
+        }
+
+        node.vv = self.dag.frontiers_to_im_vv(&change.deps);
+        self.dag
+            .map
+            .get_mut(&change.id.peer)
+            .unwrap()
+            .push_rle_element(node);
+        for dep in change.deps.iter() {
+            self.ensure_dep_on_change_end(change.id.peer, *dep);
+            let target = self.dag.get_mut(*dep).unwrap();
+            if target.ctr_last() == dep.counter {
+                target.has_succ = true;
             }
         }
 
@@ -437,19 +438,13 @@ impl OpLog {
 
             let mut temp = Vec::new();
             if let Some(peer_changes) = self.changes.get(&peer) {
-                if let Some(result) = peer_changes.get_by_atom_index(start_cnt) {
-                    for change in &peer_changes[result.merged_index..] {
-                        if change.id.counter < start_cnt {
-                            if change.id.counter + change.atom_len() as Counter <= start_cnt {
-                                continue;
-                            }
-
-                            let sliced = change
-                                .slice((start_cnt - change.id.counter) as usize, change.atom_len());
-                            temp.push(self.convert_change_to_remote(&sliced));
-                        } else {
-                            temp.push(self.convert_change_to_remote(change));
-                        }
+                for change in peer_changes.iter_from(start_cnt) {
+                    if change.id.counter < start_cnt {
+                        let sliced = change
+                            .slice((start_cnt - change.id.counter) as usize, change.atom_len());
+                        temp.push(self.convert_change_to_remote(&sliced));
+                    } else {
+                        temp.push(self.convert_change_to_remote(change));
                     }
                 }
             }
@@ -485,6 +480,40 @@ impl OpLog {
         None
     }
 
+    /// Get the changes overlapping with the given span, converted to the remote form.
+    ///
+    /// The changes at the boundaries are trimmed to fit in the span.
+    /// The part of the span that is not included in the oplog is ignored.
+    pub fn get_changes_in_span(&self, mut span: IdSpan) -> Vec<Change<RemoteOp>> {
+        span.normalize_();
+        let mut ans = Vec::new();
+        let Some(peer_changes) = self.changes.get(&span.client_id) else {
+            return ans;
+        };
+
+        let start = span.counter.start.max(0);
+        let end = span.counter.end;
+        if start >= end {
+            return ans;
+        }
+
+        for change in peer_changes.iter_from(start) {
+            if change.id.counter >= end {
+                break;
+            }
+
+            let from = (start - change.id.counter).max(0) as usize;
+            let to = ((end - change.id.counter) as usize).min(change.atom_len());
+            if from == 0 && to == change.atom_len() {
+                ans.push(self.convert_change_to_remote(change));
+            } else {
+                ans.push(self.convert_change_to_remote(&change.slice(from, to)));
+            }
+        }
+
+        ans
+    }
+
     pub fn get_remote_change_at(&self, id: ID) -> Option<Change<RemoteOp>> {
         let change = self.get_change_at(id)?;
         Some(self.convert_change_to_remote(change))
@@ -682,11 +711,7 @@ impl OpLog {
                 std::mem::swap(&mut from_cnt, &mut to_cnt);
             }
 
-            let Some(result) = changes.get_by_atom_index(from_cnt) else {
-                continue;
-            };
-
-            for change in &changes[result.merged_index..changes.len()] {
+            for change in changes.iter_from(from_cnt) {
                 if change.id.counter >= to_cnt {
                     break;
                 }
@@ -830,42 +855,114 @@ impl OpLog {
         })
     }
 
+    /// Create a new [OpLog] that only contains the history up to the given version.
+    ///
+    /// The changes that are concurrent with or after `frontiers` are excluded.
+    /// It shares the same arena with `self`.
+    ///
+    /// # Err
+    ///
+    /// - Return Err(LoroError::NotFoundError) when `frontiers` includes ids that are not in `self`
+    pub fn fork_at(&self, frontiers: &Frontiers) -> Result<OpLog, LoroError> {
+        let vv = frontiers
+            .iter()
+            .all(|id| self.dag.vv.includes_id(*id))
+            .then(|| self.dag.frontiers_to_vv(frontiers))
+            .flatten()
+            .ok_or_else(|| {
+                LoroError::NotFoundError(
+                    format!("Cannot find the specified version {:?}", frontiers).into_boxed_str(),
+                )
+            })?;
+
+        let mut changes = Vec::new();
+        self.for_each_change_within(&Default::default(), &vv, |change| {
+            let end = vv.get(&change.id.peer).copied().unwrap_or(0);
+            if change.ctr_end() > end {
+                changes.push(change.slice(0, (end - change.id.counter) as usize));
+            } else {
+                changes.push(change.clone());
+            }
+        });
+
+        // deps always have smaller lamport, so this is a causal order
+        changes.sort_by_key(|change| (change.lamport, change.id.peer));
+        let mut forked = OpLog::new_with_arena(self.arena.clone());
+        for change in changes {
+            forked.next_lamport = forked.next_lamport.max(change.lamport_end());
+            forked.latest_timestamp = forked.latest_timestamp.max(change.timestamp);
+            forked.dag.vv.extend_to_include_last_id(change.id_last());
+            let mark = forked.insert_dag_node_on_new_change(&change);
+            forked.insert_new_change(change, mark);
+        }
+
+        forked.dag.refresh_frontiers();
+        Ok(forked)
+    }
+
     pub(crate) fn len_changes(&self) -> usize {
         self.changes.values().map(|x| x.len()).sum()
     }
 
-    pub fn diagnose_size(&self) -> SizeInfo {
-        let mut total_changes = 0;
-        let mut total_ops = 0;
-        let mut total_atom_ops = 0;
-        let total_dag_node = self.dag.map.len();
-        for changes in self.changes.values() {
-            total_changes += changes.len();
+    /// Collect the size statistics of the history, in total and per peer
+    pub fn size_report(&self) -> OpLogSizeReport {
+        let mut report = OpLogSizeReport {
+            total_dag_nodes: self.dag.map.len(),
+            ..Default::default()
+        };
+        for (peer, changes) in self.changes.iter() {
+            let mut peer_report = PeerSizeReport {
+                changes: changes.len(),
+                ..Default::default()
+            };
             for change in changes.iter() {
-                total_ops += change.ops.len();
-                total_atom_ops += change.atom_len();
+                peer_report.ops += change.ops.len();
+                peer_report.atom_ops += change.atom_len();
             }
-        }
 
-        println!("total changes: {}", total_changes);
-        println!("total ops: {}", total_ops);
-        println!("total atom ops: {}", total_atom_ops);
-        println!("total dag node: {}", total_dag_node);
-        SizeInfo {
-            total_changes,
-            total_ops,
-            total_atom_ops,
-            total_dag_node,
+            report.total_changes += peer_report.changes;
+            report.total_ops += peer_report.ops;
+            report.total_atom_ops += peer_report.atom_ops;
+            report.per_peer.insert(*peer, peer_report);
         }
+
+        report
+    }
+
+    pub fn diagnose_size(&self) -> OpLogSizeReport {
+        let report = self.size_report();
+        println!("total changes: {}", report.total_changes);
+        println!("total ops: {}", report.total_ops);
+        println!("total atom ops: {}", report.total_atom_ops);
+        println!("total dag node: {}", report.total_dag_nodes);
+        report
     }
 }
 
-#[derive(Debug)]
-pub struct SizeInfo {
+/// The size statistics of an [OpLog], see [OpLog::size_report]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OpLogSizeReport {
+    /// The number of changes of all peers
     pub total_changes: usize,
+    /// The number of (merged) ops stored in the changes
     pub total_ops: usize,
+    /// The sum of the atom length of all changes, i.e. the number of ops when no merge ever happens
     pub total_atom_ops: usize,
-    pub total_dag_node: usize,
+    /// The number of peers (rows) in the dag, not the number of dag nodes
+    pub total_dag_nodes: usize,
+    /// The statistics of each peer
+    pub per_peer: FxHashMap<PeerID, PeerSizeReport>,
+}
+
+/// The size statistics of the changes of a single peer, see [OpLogSizeReport]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PeerSizeReport {
+    /// The number of changes of the peer
+    pub changes: usize,
+    /// The number of (merged) ops stored in the changes of the peer
+    pub ops: usize,
+    /// The sum of the atom length of the changes of the peer
+    pub atom_ops: usize,
 }
 
 impl Default for OpLog {
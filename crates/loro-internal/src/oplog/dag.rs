@@ -3,7 +3,7 @@ use std::cmp::Ordering;
 use crate::change::Lamport;
 use crate::dag::{Dag, DagNode};
 use crate::id::{Counter, ID};
-use crate::span::{HasId, HasLamport};
+use crate::span::{HasId, HasIdSpan, HasLamport};
 use crate::version::{Frontiers, ImVersionVector, VersionVector};
 use rle::{HasIndex, HasLength, Mergable, RleCollection, Sliceable};
 
@@ -54,18 +54,25 @@ impl HasLength for AppDagNode {
 }
 
 impl Mergable for AppDagNode {
-    fn is_mergable(&self, _other: &Self, _conf: &()) -> bool
+    /// `other` can be merged into `self` only when it's the continuation of `self`
+    /// on the same peer and it only depends on the last op of `self`
+    fn is_mergable(&self, other: &Self, _conf: &()) -> bool
     where
         Self: Sized,
     {
-        false
+        self.peer == other.peer
+            && self.cnt + self.len as Counter == other.cnt
+            && self.lamport + self.len as Lamport == other.lamport
+            && other.deps.len() == 1
+            && other.deps[0] == self.id_last()
     }
 
-    fn merge(&mut self, _other: &Self, _conf: &())
+    fn merge(&mut self, other: &Self, _conf: &())
     where
         Self: Sized,
     {
-        unreachable!()
+        self.len += other.len;
+        self.has_succ = other.has_succ;
     }
 }
 
@@ -238,3 +245,42 @@ impl AppDag {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use rle::RlePush;
+
+    use super::*;
+
+    fn node(peer: u64, cnt: Counter, lamport: Lamport, deps: &[ID], len: usize) -> AppDagNode {
+        AppDagNode {
+            peer,
+            cnt,
+            lamport,
+            deps: deps.into(),
+            vv: Default::default(),
+            has_succ: false,
+            len,
+        }
+    }
+
+    #[test]
+    fn merge_continuous_nodes() {
+        let mut row = vec![node(1, 0, 0, &[], 3)];
+        row[0].has_succ = true;
+        row.push_rle_element(node(1, 3, 3, &[ID::new(1, 2)], 2));
+        assert_eq!(row.len(), 1);
+        assert_eq!(row[0].len, 5);
+        assert!(!row[0].has_succ);
+
+        // depends on another peer
+        row.push_rle_element(node(1, 5, 5, &[ID::new(1, 4), ID::new(2, 0)], 1));
+        // lamport is not continuous
+        row.push_rle_element(node(1, 6, 10, &[ID::new(1, 5)], 1));
+        assert_eq!(row.len(), 3);
+        // depends on the middle of the last node
+        let last = node(1, 6, 10, &[], 1);
+        assert!(!last.is_mergable(&node(1, 7, 11, &[ID::new(1, 3)], 1), &()));
+        assert!(last.is_mergable(&node(1, 7, 11, &[ID::new(1, 6)], 1), &()));
+    }
+}
@@ -1,4 +1,7 @@
-use std::{fmt::Debug, ops::Deref};
+use std::{
+    fmt::Debug,
+    ops::{Deref, Range},
+};
 
 use append_only_bytes::BytesSlice;
 use serde::{Deserialize, Deserializer, Serialize};
@@ -75,6 +78,28 @@ impl StringSlice {
         }
     }
 
+    /// Get the sub-slice in the given byte range.
+    ///
+    /// It's zero-copy when `self` is backed by [BytesSlice].
+    /// Return None if the range is out of bound or is not on utf8 char boundaries.
+    pub fn byte_slice(&self, range: Range<usize>) -> Option<StringSlice> {
+        let s = self.as_str();
+        if range.start > range.end
+            || range.end > s.len()
+            || !s.is_char_boundary(range.start)
+            || !s.is_char_boundary(range.end)
+        {
+            return None;
+        }
+
+        let bytes = match &self.bytes {
+            Variant::BytesSlice(b) => Variant::BytesSlice(b.slice_clone(range)),
+            Variant::Owned(o) => Variant::Owned(o[range].to_string()),
+        };
+
+        Some(Self { bytes })
+    }
+
     pub fn len_bytes(&self) -> usize {
         match &self.bytes {
             Variant::BytesSlice(s) => s.len(),
@@ -215,3 +240,25 @@ pub fn unicode_range_to_byte_range(bytes: &[u8], start: usize, end: usize) -> (u
 
     (start_byte, end_byte)
 }
+
+#[cfg(test)]
+mod test {
+    use append_only_bytes::AppendOnlyBytes;
+
+    use super::*;
+
+    #[test]
+    fn byte_slice() {
+        let mut bytes = AppendOnlyBytes::default();
+        bytes.push_str("ab你好");
+        let slice = StringSlice::new(bytes.slice(..));
+        assert_eq!(slice.byte_slice(1..5).unwrap().as_str(), "b你");
+        assert_eq!(slice.byte_slice(0..0).unwrap().as_str(), "");
+        assert!(slice.byte_slice(1..3).is_none());
+        assert!(slice.byte_slice(2..9).is_none());
+
+        let owned = StringSlice::from("ab你好");
+        assert_eq!(owned.byte_slice(2..8).unwrap().as_str(), "你好");
+        assert!(owned.byte_slice(3..8).is_none());
+    }
+}
@@ -130,12 +130,70 @@ impl Frontiers {
     }
 
     pub fn retain_non_included(&mut self, other: &Frontiers) {
-        self.retain(|id| !other.contains(id));
+        self.retain(|id| !other.contains(*id));
     }
 
     pub fn filter_peer(&mut self, peer: PeerID) {
         self.retain(|id| id.peer != peer);
     }
+
+    #[inline]
+    pub fn contains(&self, id: ID) -> bool {
+        self.0.contains(&id)
+    }
+
+    /// Merge two frontiers, keeping only the maximal id of each peer.
+    ///
+    /// The result may include ids that are causally dominated by the others,
+    /// use [Frontiers::retain_causally_maximal] to make it minimal.
+    pub fn union(&self, other: &Frontiers) -> Frontiers {
+        let mut ans = self.clone();
+        for id in other.iter() {
+            match ans.iter_mut().find(|x| x.peer == id.peer) {
+                Some(x) => {
+                    if x.counter < id.counter {
+                        *x = *id;
+                    }
+                }
+                None => ans.push(*id),
+            }
+        }
+
+        ans
+    }
+
+    /// Remove the ids that are causally dominated by other ids in the frontiers,
+    /// so the frontiers stay minimal.
+    ///
+    /// # Panic
+    ///
+    /// When the frontiers include ids that are not in the dag
+    pub fn retain_causally_maximal(&mut self, dag: &AppDag) {
+        for id in self.iter() {
+            // `get_vv` also accepts the id right after the end, so check it explicitly
+            assert!(dag.vv.includes_id(*id), "{:?} is not in the dag", id);
+        }
+
+        if self.len() <= 1 {
+            return;
+        }
+
+        let vv_list: Vec<ImVersionVector> =
+            self.iter().map(|id| dag.get_vv(*id).unwrap()).collect();
+        let ids = std::mem::take(&mut self.0);
+        for (i, id) in ids.iter().enumerate() {
+            let dominated = vv_list.iter().enumerate().any(|(j, vv)| {
+                i != j
+                    && ids[j] != *id
+                    && vv
+                        .get(&id.peer)
+                        .is_some_and(|&counter| counter > id.counter)
+            });
+            if !dominated && !self.0.contains(id) {
+                self.0.push(*id);
+            }
+        }
+    }
 }
 
 impl Deref for Frontiers {
@@ -1106,6 +1164,17 @@ impl PartialOrd for PatchedVersionVector {
 mod tests {
     #![allow(clippy::neg_cmp_op_on_partial_ord)]
     use super::*;
+
+    #[test]
+    fn frontiers_union() {
+        let a: Frontiers = vec![ID::new(1, 1), ID::new(2, 5)].into();
+        let b: Frontiers = vec![ID::new(2, 3), ID::new(3, 0)].into();
+        let c = a.union(&b);
+        assert_eq!(c, vec![ID::new(1, 1), ID::new(2, 5), ID::new(3, 0)].into());
+        assert_eq!(c.len(), 3);
+        assert!(c.contains(ID::new(2, 5)));
+        assert!(!c.contains(ID::new(2, 3)));
+    }
     mod cmp {
         use super::*;
         #[test]
@@ -1,9 +1,10 @@
 use std::sync::{atomic::AtomicBool, Arc, Mutex};
 
-use loro_common::{ContainerID, ContainerType, LoroValue, ID};
+use loro_common::{ContainerID, ContainerType, IdSpan, LoroValue, ID};
 use loro_internal::{
     container::richtext::TextStyleInfoFlag, version::Frontiers, ApplyDiff, LoroDoc, ToJson,
 };
+use rle::HasLength;
 use serde_json::json;
 
 #[test]
@@ -409,6 +410,150 @@ fn test_timestamp() {
     assert!(change.timestamp() > 1690966970);
 }
 
+#[test]
+fn oplog_size_report() {
+    let a = LoroDoc::new_auto_commit();
+    a.set_peer_id(1).unwrap();
+    a.get_text("text").insert_(0, "123").unwrap();
+    a.commit_then_renew();
+    let b = LoroDoc::new_auto_commit();
+    b.set_peer_id(2).unwrap();
+    b.get_list("list").insert_(0, "x".into()).unwrap();
+    b.get_list("list").insert_(1, "y".into()).unwrap();
+    b.commit_then_renew();
+    a.import(&b.export_from(&Default::default())).unwrap();
+
+    let oplog = a.oplog().lock().unwrap();
+    let report = oplog.size_report();
+    assert_eq!(report.per_peer.len(), 2);
+    assert_eq!(report.per_peer[&1].atom_ops, 3);
+    assert_eq!(report.per_peer[&2].atom_ops, 2);
+    assert_eq!(report.total_atom_ops, 5);
+    assert_eq!(
+        report.total_changes,
+        report.per_peer.values().map(|x| x.changes).sum::<usize>()
+    );
+    assert_eq!(
+        report.total_ops,
+        report.per_peer.values().map(|x| x.ops).sum::<usize>()
+    );
+    // it counts the dag rows, one per peer
+    assert_eq!(report.total_dag_nodes, report.per_peer.len());
+    assert_eq!(oplog.diagnose_size(), report);
+}
+
+#[test]
+fn get_changes_in_span() {
+    let doc = LoroDoc::new();
+    doc.set_peer_id(1).unwrap();
+    let text = doc.get_text("text");
+    let mut txn = doc.txn().unwrap();
+    text.insert(&mut txn, 0, "hello").unwrap();
+    txn.commit().unwrap();
+    let mut txn = doc.txn().unwrap();
+    text.insert(&mut txn, 5, " world").unwrap();
+    txn.commit().unwrap();
+
+    let oplog = doc.oplog().lock().unwrap();
+    let changes = oplog.get_changes_in_span(IdSpan::new(1, 2, 7));
+    assert_eq!(changes[0].id, ID::new(1, 2));
+    assert_eq!(changes.iter().map(|c| c.atom_len()).sum::<usize>(), 5);
+
+    // clamp to the existing counters
+    let changes = oplog.get_changes_in_span(IdSpan::new(1, 8, 100));
+    assert_eq!(changes[0].id, ID::new(1, 8));
+    assert_eq!(changes.iter().map(|c| c.atom_len()).sum::<usize>(), 3);
+
+    assert!(oplog.get_changes_in_span(IdSpan::new(1, 11, 20)).is_empty());
+    assert!(oplog.get_changes_in_span(IdSpan::new(2, 0, 20)).is_empty());
+}
+
+#[test]
+fn frontiers_retain_causally_maximal() {
+    let a = LoroDoc::new_auto_commit();
+    a.set_peer_id(1).unwrap();
+    a.get_text("text").insert_(0, "a").unwrap();
+    a.commit_then_renew();
+    let b = LoroDoc::new_auto_commit();
+    b.set_peer_id(2).unwrap();
+    b.import(&a.export_from(&Default::default())).unwrap();
+    b.get_text("text").insert_(0, "b").unwrap();
+    b.commit_then_renew();
+    let c = LoroDoc::new_auto_commit();
+    c.set_peer_id(3).unwrap();
+    c.get_text("text").insert_(0, "c").unwrap();
+    c.commit_then_renew();
+    b.import(&c.export_from(&Default::default())).unwrap();
+
+    let oplog = b.oplog().lock().unwrap();
+    let mut f = Frontiers::from(ID::new(1, 0)).union(&vec![ID::new(2, 0), ID::new(3, 0)].into());
+    assert_eq!(f.len(), 3);
+    f.retain_causally_maximal(oplog.dag());
+    assert_eq!(f.len(), 2);
+    assert_eq!(f, vec![ID::new(2, 0), ID::new(3, 0)].into());
+}
+
+#[test]
+#[should_panic]
+fn frontiers_retain_causally_maximal_unknown_id() {
+    let a = LoroDoc::new_auto_commit();
+    a.set_peer_id(1).unwrap();
+    a.get_text("text").insert_(0, "a").unwrap();
+    a.commit_then_renew();
+    let oplog = a.oplog().lock().unwrap();
+    // the id right after the last op
+    let mut f: Frontiers = vec![ID::new(1, 0), ID::new(1, 1)].into();
+    f.retain_causally_maximal(oplog.dag());
+}
+
+#[test]
+fn oplog_fork_at() {
+    let a = LoroDoc::new_auto_commit();
+    a.set_peer_id(1).unwrap();
+    a.get_text("text").insert_(0, "hello").unwrap();
+    a.commit_then_renew();
+    let v1 = a.oplog_frontiers();
+    let b = LoroDoc::new_auto_commit();
+    b.set_peer_id(2).unwrap();
+    b.import(&a.export_from(&Default::default())).unwrap();
+    b.get_text("text").insert_(5, " world").unwrap();
+    b.commit_then_renew();
+    a.get_text("text").insert_(0, "!").unwrap();
+    a.commit_then_renew();
+    a.import(&b.export_from(&Default::default())).unwrap();
+
+    // `oplog_frontiers` and `get_deep_value` lock the oplog, so read them before locking it
+    let latest = a.oplog_frontiers();
+    let latest_value = a.get_deep_value();
+    let oplog = a.oplog().lock().unwrap();
+    let forked = oplog.fork_at(&v1).unwrap();
+    assert_eq!(forked.dag().get_frontiers(), &v1);
+    assert_eq!(forked.size_report().total_atom_ops, 5);
+    let doc = LoroDoc::new();
+    doc.import(&forked.export_from(&Default::default())).unwrap();
+    assert_eq!(
+        doc.get_deep_value().to_json_value(),
+        json!({"text": "hello"})
+    );
+
+    // trim the change at the boundary
+    let forked = oplog.fork_at(&ID::new(1, 2).into()).unwrap();
+    assert_eq!(forked.size_report().total_atom_ops, 3);
+
+    let forked = oplog.fork_at(&latest).unwrap();
+    assert_eq!(forked.dag().get_frontiers(), &latest);
+    assert_eq!(
+        forked.size_report().total_atom_ops,
+        oplog.size_report().total_atom_ops
+    );
+    let doc = LoroDoc::new();
+    doc.import(&forked.export_from(&Default::default())).unwrap();
+    assert_eq!(doc.get_deep_value(), latest_value);
+
+    assert!(oplog.fork_at(&ID::new(1, 100).into()).is_err());
+    assert!(oplog.fork_at(&ID::new(3, 0).into()).is_err());
+}
+
 #[test]
 fn test_text_checkout() {
     let mut doc = LoroDoc::new();
@@ -0,0 +1,93 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use rle::{HasIndex, HasLength, Mergable, RleVec, Sliceable};
+
+/// A run of text whose atom is a unicode char.
+/// Locating an atom inside a run needs a linear scan over its chars.
+#[derive(Debug, Clone)]
+struct TextRun {
+    start: usize,
+    len: usize,
+    text: String,
+}
+
+impl HasLength for TextRun {
+    fn content_len(&self) -> usize {
+        self.len
+    }
+}
+
+impl HasIndex for TextRun {
+    type Int = usize;
+
+    fn get_start_index(&self) -> Self::Int {
+        self.start
+    }
+}
+
+impl Mergable for TextRun {
+    fn is_mergable(&self, other: &Self, _: &()) -> bool {
+        self.start + self.len == other.start
+    }
+
+    fn merge(&mut self, other: &Self, _: &()) {
+        self.len += other.len;
+        self.text.push_str(&other.text);
+    }
+}
+
+impl Sliceable for TextRun {
+    fn slice(&self, from: usize, to: usize) -> Self {
+        TextRun {
+            start: self.start + from,
+            len: to - from,
+            text: self.text.chars().skip(from).take(to - from).collect(),
+        }
+    }
+}
+
+const N: usize = 100_000;
+
+fn build(max_run: Option<usize>) -> RleVec<[TextRun; 0]> {
+    let mut vec = RleVec::new();
+    for i in 0..N {
+        let run = TextRun {
+            start: i,
+            len: 1,
+            text: "你".to_string(),
+        };
+        match max_run {
+            Some(max) => vec.push_with_max_run(run, max),
+            None => vec.push(run),
+        }
+    }
+
+    vec
+}
+
+fn get_char(vec: &RleVec<[TextRun; 0]>, index: usize) -> char {
+    let result = vec.get_by_atom_index(index).unwrap();
+    result.element.text.chars().nth(result.offset).unwrap()
+}
+
+pub fn get_by_atom_index(c: &mut Criterion) {
+    let mut b = c.benchmark_group("RleVec get_by_atom_index");
+    let uncapped = build(None);
+    let capped = build(Some(64));
+    b.bench_function("uncapped run", |b| {
+        let mut index = 0;
+        b.iter(|| {
+            index = (index + 7919) % N;
+            get_char(&uncapped, index)
+        })
+    });
+    b.bench_function("run capped at 64", |b| {
+        let mut index = 0;
+        b.iter(|| {
+            index = (index + 7919) % N;
+            get_char(&capped, index)
+        })
+    });
+}
+
+criterion_group!(benches, get_by_atom_index);
+criterion_main!(benches);
@@ -28,6 +28,7 @@ mod rle_vec;
 mod rle_vec_old;
 pub use crate::rle_trait::{
     HasIndex, HasLength, Mergable, Rle, RleCollection, RlePush, Slice, Sliceable, ZeroElement,
+    ZeroRun,
 };
 pub use crate::rle_vec::{slice_vec_by, RleVec, RleVecWithLen};
 pub use crate::rle_vec_old::{RleVecWithIndex, SearchResult, SliceIterator};
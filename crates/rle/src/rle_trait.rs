@@ -81,6 +81,12 @@ pub trait HasIndex: HasLength {
     }
 }
 
+/// A [ZeroElement] that can be placed at the given index, e.g. to represent a gap.
+pub trait ZeroRun: ZeroElement + HasIndex {
+    /// Create a zero run that covers the atom indexes `start..end`.
+    fn zero_run(start: Self::Int, end: Self::Int) -> Self;
+}
+
 pub trait GlobalIndex:
     Debug + Integer + Copy + Default + FromPrimitive + AsPrimitive<usize>
 {
@@ -125,6 +131,11 @@ pub trait RleCollection<T: HasIndex> {
         &self,
         index: <T as HasIndex>::Int,
     ) -> Option<SearchResult<'_, T, <T as HasIndex>::Int>>;
+    /// Iterate over the elements starting from the one that contains the given atom index.
+    ///
+    /// The first yielded item is the **whole** containing element, the caller should trim it
+    /// when needed. It's empty if the index is not less than [RleCollection::end].
+    fn iter_from(&self, index: <T as HasIndex>::Int) -> std::slice::Iter<'_, T>;
 }
 
 impl<T: Mergable> RlePush<T> for Vec<T> {
@@ -183,6 +194,23 @@ impl<T: Mergable + HasIndex + HasLength> RleCollection<T> for Vec<T> {
         })
     }
 
+    fn iter_from(&self, index: <T as HasIndex>::Int) -> std::slice::Iter<'_, T> {
+        if self.is_empty() || index >= self.end() {
+            return self[self.len()..].iter();
+        }
+
+        if index <= self.start() {
+            return self.iter();
+        }
+
+        let mut merged_index = self.search_atom_index(index);
+        if self[merged_index].get_end_index() <= index {
+            merged_index += 1;
+        }
+
+        self[merged_index..].iter()
+    }
+
     fn start(&self) -> <T as HasIndex>::Int {
         self.first()
             .map(|x| x.get_start_index())
@@ -1,7 +1,7 @@
 use std::{collections::HashMap, ptr::NonNull};
 
 use self::node::{InternalNode, LeafNode, Node};
-use crate::Rle;
+use crate::{HasLength, Rle};
 use bumpalo::collections::vec::Vec as BumpVec;
 pub use cursor::{SafeCursor, SafeCursorMut, UnsafeCursor};
 use fxhash::FxHashMap;
@@ -481,6 +481,25 @@ impl<T: Rle, A: RleTreeTrait<T>> RleTree<T, A> {
         }
     }
 
+    /// Create a snapshot of the tree that is fully independent of `self`.
+    ///
+    /// The node hierarchy is deep cloned into a new arena, so the snapshot can be sent to
+    /// another thread while `self` is being modified. It costs O(n) time and memory, where n
+    /// is the number of elements, since every node is allocated again and every element is cloned.
+    ///
+    /// The pointers to the leaf nodes that were passed to the `notify` callbacks are not valid
+    /// for the snapshot.
+    pub fn clone_structure(&self) -> Self {
+        RleTreeBuilder {
+            bump: Default::default(),
+            node_builder: |bump: &A::Arena| {
+                let mut last_leaf = None;
+                self.root().clone_in(bump, None, &mut last_leaf)
+            },
+        }
+        .build()
+    }
+
     pub fn debug_check(&mut self) {
         self.with_node_mut(|node| {
             node.as_internal_mut().unwrap().check();
@@ -535,9 +554,71 @@ impl<T: Rle, A: RleTreeTrait<T>> RleTree<T, A> {
         })
     }
 
+    /// Collect the occupancy statistics of the tree by traversing all the nodes once.
+    ///
+    /// The fill of a leaf node is the ratio of its children number to `A::MAX_CHILDREN_NUM`.
+    pub fn stats(&self) -> RleTreeStats {
+        let mut stats = RleTreeStats::default();
+        let mut node = self.root();
+        loop {
+            stats.height += 1;
+            match node {
+                Node::Internal(internal_node) => match internal_node.children.first() {
+                    Some(child) => node = &child.node,
+                    None => break,
+                },
+                Node::Leaf(_) => break,
+            }
+        }
+
+        let mut min_leaf_len = usize::MAX;
+        let mut max_leaf_len = 0;
+        self.root().recursive_visit_all(&mut |node| match node {
+            Node::Internal(_) => stats.internal_nodes += 1,
+            Node::Leaf(leaf) => {
+                let len = leaf.children.len();
+                stats.leaf_nodes += 1;
+                stats.total_elements += len;
+                stats.total_atoms += leaf
+                    .children
+                    .iter()
+                    .map(HasLength::content_len)
+                    .sum::<usize>();
+                min_leaf_len = min_leaf_len.min(len);
+                max_leaf_len = max_leaf_len.max(len);
+            }
+        });
+
+        if stats.leaf_nodes > 0 {
+            let max_children = A::MAX_CHILDREN_NUM as f64;
+            stats.min_leaf_fill = min_leaf_len as f64 / max_children;
+            stats.max_leaf_fill = max_leaf_len as f64 / max_children;
+            stats.avg_leaf_fill =
+                stats.total_elements as f64 / (stats.leaf_nodes as f64 * max_children);
+        }
+
+        stats
+    }
+
     // pub fn iter_cursor_mut(&mut self) -> impl Iterator<Item = SafeCursorMut<'_, T, A>> {}
 }
 
+/// The occupancy statistics of a [RleTree], see [RleTree::stats]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RleTreeStats {
+    /// The number of levels, including the root and the leaf level
+    pub height: usize,
+    pub internal_nodes: usize,
+    pub leaf_nodes: usize,
+    /// The number of (merged) elements stored in the leaf nodes
+    pub total_elements: usize,
+    /// The sum of [HasLength::content_len] of all elements, i.e. the logical length of the tree
+    pub total_atoms: usize,
+    pub min_leaf_fill: f64,
+    pub max_leaf_fill: f64,
+    pub avg_leaf_fill: f64,
+}
+
 impl<T: Rle, A: RleTreeTrait<T>> RleTree<T, A> {
     #[inline]
     pub fn len(&self) -> A::Int {
@@ -332,6 +332,52 @@ impl<'a, T: Rle, A: RleTreeTrait<T>> Node<'a, T, A> {
         }
     }
 
+    /// Deep clone this node and its descendants into `bump`.
+    ///
+    /// `last_leaf` is the last leaf cloned before this node. The cloned leaves are linked
+    /// to it, so the nodes should be cloned in order.
+    pub(crate) fn clone_in<'b>(
+        &self,
+        bump: &'b A::Arena,
+        parent: Option<NonNull<InternalNode<'b, T, A>>>,
+        last_leaf: &mut Option<NonNull<LeafNode<'b, T, A>>>,
+    ) -> ArenaBoxedNode<'b, T, A> {
+        match self {
+            Node::Internal(node) => {
+                let mut ans = bump.allocate(Node::Internal(InternalNode::new(bump, parent)));
+                let ans_inner = ans.as_internal_mut().unwrap();
+                let ans_ptr = NonNull::from(&mut *ans_inner);
+                for child in node.children.iter() {
+                    ans_inner.children.push(Child {
+                        node: child.node.clone_in(bump, Some(ans_ptr), last_leaf),
+                        parent_cache: child.parent_cache,
+                    });
+                }
+
+                ans_inner.cache = node.cache;
+                ans
+            }
+            Node::Leaf(node) => {
+                let mut ans = bump.allocate(Node::Leaf(LeafNode::new(bump, parent.unwrap())));
+                let ans_inner = ans.as_leaf_mut().unwrap();
+                for child in node.children.iter() {
+                    ans_inner.children.push(child.clone());
+                }
+
+                ans_inner.cache = node.cache;
+                let ans_ptr = NonNull::from(&mut *ans_inner);
+                ans_inner.prev = *last_leaf;
+                if let Some(mut prev) = *last_leaf {
+                    // SAFETY: prev is a leaf cloned before, it's allocated in the same arena and not borrowed
+                    unsafe { prev.as_mut().next = Some(ans_ptr) };
+                }
+
+                *last_leaf = Some(ans_ptr);
+                ans
+            }
+        }
+    }
+
     #[inline(always)]
     pub fn cache(&self) -> A::Cache {
         match self {
@@ -474,7 +474,7 @@ impl<'bump, T: Rle, A: RleTreeTrait<T>> LeafNode<'bump, T, A> {
             ans.append(&mut end);
         }
 
-        debug_assert_eq!(ans_len, ans.iter().map(|x| x.atom_len()).sum());
+        debug_assert_eq!(ans_len, ans.iter().map(|x| x.atom_len()).sum::<usize>());
         ans
     }
 
@@ -117,3 +117,61 @@ fn delete_that_causes_increase_levels() {
         tree.debug_check();
     }
 }
+
+#[test]
+fn stats() {
+    let mut tree: RleTree<Range<usize>, RangeTreeTrait> = RleTree::default();
+    let stats = tree.stats();
+    assert_eq!(stats.height, 1);
+    assert_eq!(stats.leaf_nodes, 0);
+    assert_eq!(stats.total_atoms, 0);
+
+    for i in (0..2000).step_by(2) {
+        tree.insert(tree.len(), i..i + 1);
+    }
+
+    let stats = tree.stats();
+    assert_eq!(stats.total_atoms, tree.len());
+    assert_eq!(stats.total_elements, tree.iter().count());
+    assert!(stats.height > 2);
+    assert!(stats.min_leaf_fill <= stats.avg_leaf_fill);
+    assert!(stats.avg_leaf_fill <= stats.max_leaf_fill);
+    assert!(stats.avg_leaf_fill >= 0.5);
+}
+
+#[test]
+fn clone_structure() {
+    let mut tree: RleTree<Range<usize>, RangeTreeTrait> = RleTree::default();
+    for i in (0..1000).step_by(2) {
+        tree.insert(tree.len(), i..i + 1);
+    }
+
+    let snapshot = tree.clone_structure();
+    let expected: Vec<_> = tree.iter().map(|x| x.as_ref().clone()).collect();
+    assert_eq!(snapshot.len(), tree.len());
+    assert!(snapshot
+        .iter()
+        .map(|x| x.as_ref().clone())
+        .eq(expected.clone()));
+    assert_eq!(
+        snapshot.iter_range(10, Some(20)).count(),
+        tree.iter_range(10, Some(20)).count()
+    );
+
+    tree.insert(5, 2000..2010);
+    tree.delete_range(Some(100), Some(300));
+    tree.debug_check();
+    assert_eq!(snapshot.len(), 500);
+    assert!(snapshot.iter().map(|x| x.as_ref().clone()).eq(expected));
+
+    let snapshot = std::thread::spawn(move || {
+        let mut snapshot = snapshot;
+        snapshot.debug_check();
+        snapshot.insert(0, 3000..3001);
+        snapshot
+    })
+    .join()
+    .unwrap();
+    assert_eq!(snapshot.len(), 501);
+    assert_eq!(tree.len(), 310);
+}
@@ -191,7 +191,7 @@ impl<T: Rle, const MAX_CHILD: usize, TreeArena: Arena> RleTreeTrait<T>
                             debug_assert_eq!(x.parent_cache, x.node.cache());
                             x.parent_cache
                         })
-                        .sum()
+                        .sum::<isize>()
                 )
             }
             None => {
@@ -7,7 +7,10 @@ use std::{
 use num::{traits::AsPrimitive, FromPrimitive};
 use smallvec::{Array, SmallVec};
 
-use crate::{rle_trait::HasIndex, HasLength, Mergable, SearchResult, SliceIterator, Sliceable};
+use crate::{
+    rle_trait::{HasIndex, ZeroRun},
+    HasLength, Mergable, SearchResult, SliceIterator, Sliceable,
+};
 
 /// RleVec<T> is a vector that can be compressed using run-length encoding.
 ///
@@ -40,6 +43,15 @@ where
         self.vec.push(value);
     }
 
+    /// See [RleVec::push_with_max_run]
+    pub fn push_with_max_run(&mut self, value: A::Item, max_atom_len: usize)
+    where
+        A::Item: Sliceable,
+    {
+        self.atom_len += value.atom_len();
+        self.vec.push_with_max_run(value, max_atom_len);
+    }
+
     pub fn new() -> Self {
         Self {
             vec: Default::default(),
@@ -266,6 +278,61 @@ where
 
         self.vec.push(value);
     }
+
+    /// push a new element to the end of the array. It may be merged with last element,
+    /// but only if the merged element's atom length would not exceed `max_atom_len`.
+    /// If `value` itself is longer than `max_atom_len`, it's split into multiple elements.
+    ///
+    /// It bounds the length of every element pushed by this method, so the operations whose cost
+    /// is linear to the length of a single element (e.g. locating an atom inside it) stay cheap.
+    ///
+    /// # Panic
+    ///
+    /// When `max_atom_len` is zero
+    pub fn push_with_max_run(&mut self, mut value: A::Item, max_atom_len: usize)
+    where
+        A::Item: Sliceable,
+    {
+        assert!(max_atom_len > 0, "max_atom_len should be greater than 0");
+        while value.atom_len() > max_atom_len {
+            self.vec.push(value.slice(0, max_atom_len));
+            value = value.slice(max_atom_len, value.atom_len());
+        }
+
+        if let Some(last) = self.vec.last_mut() {
+            if last.atom_len() + value.atom_len() <= max_atom_len && last.is_mergable(&value, &()) {
+                last.merge(&value, &());
+                return;
+            }
+        }
+
+        self.vec.push(value);
+    }
+
+    /// Merge the adjacent elements that are mergable in a single left-to-right pass.
+    ///
+    /// The elements are only merged at the edit site when they are pushed. But if the vec is
+    /// edited through [RleVec::vec_mut], there may be adjacent elements that can be merged.
+    /// This method doesn't change the content, it only reduces the number of merged elements.
+    /// It's idempotent.
+    pub fn compact(&mut self) {
+        if self.vec.len() < 2 {
+            return;
+        }
+
+        let mut last = 0;
+        for i in 1..self.vec.len() {
+            let (left, right) = self.vec.split_at_mut(i);
+            if left[last].is_mergable(&right[0], &()) {
+                left[last].merge(&right[0], &());
+            } else {
+                last += 1;
+                self.vec.swap(last, i);
+            }
+        }
+
+        self.vec.truncate(last + 1);
+    }
 }
 impl<A: Array> RleVec<A>
 where
@@ -345,6 +412,31 @@ where
         })
     }
 
+    /// Iterate over the merged elements starting from the one that contains the given atom index.
+    ///
+    /// If the index lands inside a merged element, the first yielded item is the
+    /// **whole** containing element, not a truncated one. The caller should trim it
+    /// when needed. If the index falls into a gap between two elements, the iteration
+    /// starts from the next element. If the index is not less than [RleVec::end],
+    /// the iterator is empty.
+    pub fn iter_from(&self, index: <A::Item as HasIndex>::Int) -> std::slice::Iter<'_, A::Item> {
+        if self.vec.is_empty() || index >= self.end() {
+            return self.vec[self.vec.len()..].iter();
+        }
+
+        if index <= self.vec[0].get_start_index() {
+            return self.vec.iter();
+        }
+
+        let mut merged_index = self.search_atom_index(index);
+        if self.vec[merged_index].get_end_index() <= index {
+            // index lands on a gap between two elements
+            merged_index += 1;
+        }
+
+        self.vec[merged_index..].iter()
+    }
+
     pub fn search_atom_index(&self, index: <<A as Array>::Item as HasIndex>::Int) -> usize {
         let mut start = 0;
         let mut end = self.vec.len() - 1;
@@ -439,6 +531,52 @@ where
     }
 }
 
+impl<A: Array> RleVec<A>
+where
+    A::Item: Mergable + HasLength + Sliceable + ZeroRun,
+{
+    /// Append a zero run to extend [RleVec::end] up to `atom_len`, so every atom index
+    /// less than `atom_len` is covered, same as [RleVec::get_or_zero].
+    /// The zero run may be merged with the last element, e.g. a trailing zero run.
+    ///
+    /// `atom_len` is an absolute atom index rather than a length counted from the first
+    /// element. It does nothing if [RleVec::end] is not less than `atom_len`.
+    pub fn fill_gaps_to(&mut self, atom_len: <A::Item as HasIndex>::Int) {
+        let end = self.end();
+        if end < atom_len {
+            self.push(A::Item::zero_run(end, atom_len));
+        }
+    }
+
+    /// Get the atom element at the given atom index.
+    ///
+    /// If the index is out of range or inside a gap, return the zero run of the atom,
+    /// i.e. `T::zero_run(atom_index, atom_index + 1)`. So a gap reads the same as the
+    /// zero run filled by [RleVec::fill_gaps_to].
+    pub fn get_or_zero(&self, atom_index: <A::Item as HasIndex>::Int) -> A::Item {
+        let zero = || {
+            A::Item::zero_run(
+                atom_index,
+                atom_index + <A::Item as HasIndex>::Int::from_usize(1).unwrap(),
+            )
+        };
+        if self.vec.is_empty()
+            || atom_index < self.vec[0].get_start_index()
+            || atom_index >= self.end()
+        {
+            return zero();
+        }
+
+        match self.get_by_atom_index(atom_index) {
+            Some(result) if result.offset.as_() < result.element.atom_len() => {
+                let offset = result.offset.as_();
+                result.element.slice(offset, offset + 1)
+            }
+            _ => zero(),
+        }
+    }
+}
+
 impl<A: Array> From<Vec<A::Item>> for RleVec<A>
 where
     A::Item: Mergable + HasLength,
@@ -651,6 +789,66 @@ where
     ans
 }
 
+#[cfg(feature = "serde")]
+mod serde_impl {
+    use super::*;
+    use serde::{
+        de::{SeqAccess, Visitor},
+        Deserialize, Deserializer, Serialize, Serializer,
+    };
+
+    /// The merged elements are serialized as-is, so the run structure is preserved
+    impl<A: Array> Serialize for RleVec<A>
+    where
+        A::Item: Serialize,
+    {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            serializer.collect_seq(self.vec.iter())
+        }
+    }
+
+    /// Every element is pushed through [RleVec::push], so the adjacent mergeable elements are merged
+    impl<'de, A: Array> Deserialize<'de> for RleVec<A>
+    where
+        A::Item: Deserialize<'de> + Mergable + HasLength,
+    {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            struct RleVecVisitor<A>(PhantomData<fn() -> A>);
+
+            impl<'de, A: Array> Visitor<'de> for RleVecVisitor<A>
+            where
+                A::Item: Deserialize<'de> + Mergable + HasLength,
+            {
+                type Value = RleVec<A>;
+
+                fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                    f.write_str("a sequence of rle elements")
+                }
+
+                fn visit_seq<S>(self, mut seq: S) -> Result<Self::Value, S::Error>
+                where
+                    S: SeqAccess<'de>,
+                {
+                    let mut ans = RleVec::new();
+                    while let Some(item) = seq.next_element()? {
+                        ans.push(item);
+                    }
+
+                    Ok(ans)
+                }
+            }
+
+            deserializer.deserialize_seq(RleVecVisitor(PhantomData))
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -675,4 +873,176 @@ mod test {
             println!("{:?}", k);
         }
     }
+
+    #[test]
+    fn push_with_max_run() {
+        let mut a: RleVecWithLen<[Range<usize>; 4]> = RleVecWithLen::new();
+        for i in 0..10 {
+            a.push_with_max_run(i..i + 1, 4);
+        }
+        assert_eq!(a.atom_len(), 10);
+        assert_eq!(&**a.vec(), &[0..4, 4..8, 8..10]);
+        a.check();
+
+        // oversized values are split
+        a.push_with_max_run(10..20, 4);
+        assert_eq!(a.atom_len(), 20);
+        assert_eq!(&**a.vec(), &[0..4, 4..8, 8..10, 10..14, 14..18, 18..20]);
+        a.check();
+
+        let mut b: RleVec<[Range<usize>; 4]> = RleVec::new();
+        b.push_with_max_run(0..8, 4);
+        b.push_with_max_run(8..9, 4);
+        assert_eq!(&**b.vec(), &[0..4, 4..8, 8..9]);
+        assert!(b.iter().all(|x| x.atom_len() <= 4));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_preserves_runs() {
+        let mut a: RleVec<[Range<usize>; 4]> = RleVec::new();
+        a.push(0..500_000);
+        a.push(500_000..1_000_000);
+        a.push(2_000_000..2_000_010);
+        a.push(3_000_000..3_000_010);
+        assert_eq!(a.merged_len(), 3);
+        let json = serde_json::to_string(&a).unwrap();
+        assert_eq!(
+            json,
+            r#"[{"start":0,"end":1000000},{"start":2000000,"end":2000010},{"start":3000000,"end":3000010}]"#
+        );
+        let b: RleVec<[Range<usize>; 4]> = serde_json::from_str(&json).unwrap();
+        assert_eq!(a, b);
+        assert_eq!(a.atom_len(), b.atom_len());
+
+        // unmerged input is merged again on deserialization
+        let c: RleVec<[Range<usize>; 4]> =
+            serde_json::from_str(r#"[{"start":0,"end":5},{"start":5,"end":10}]"#).unwrap();
+        let mut d: RleVec<[Range<usize>; 4]> = RleVec::new();
+        for i in 0..10 {
+            d.push(i..i + 1);
+        }
+        assert_eq!(c, d);
+        assert_eq!(c.merged_len(), 1);
+    }
+
+    #[test]
+    fn compact() {
+        let mut a: RleVec<[Range<usize>; 4]> = RleVec::new();
+        a.compact();
+        assert_eq!(a.merged_len(), 0);
+        a.vec_mut()
+            .extend([0..2, 2..4, 4..5, 8..10, 10..12, 20..21]);
+        a.compact();
+        assert_eq!(&**a.vec(), &[0..5, 8..12, 20..21]);
+        assert_eq!(a.atom_len(), 21);
+        let b = a.clone();
+        a.compact();
+        assert_eq!(a, b);
+    }
+
+    #[derive(Debug, Clone, Default, PartialEq, Eq)]
+    struct Run {
+        start: usize,
+        len: usize,
+        value: u32,
+    }
+
+    impl HasLength for Run {
+        fn content_len(&self) -> usize {
+            self.len
+        }
+    }
+
+    impl HasIndex for Run {
+        type Int = usize;
+
+        fn get_start_index(&self) -> Self::Int {
+            self.start
+        }
+    }
+
+    impl Mergable for Run {
+        fn is_mergable(&self, other: &Self, _: &()) -> bool {
+            self.start + self.len == other.start && self.value == other.value
+        }
+
+        fn merge(&mut self, other: &Self, _: &()) {
+            self.len += other.len;
+        }
+    }
+
+    impl Sliceable for Run {
+        fn slice(&self, from: usize, to: usize) -> Self {
+            Run {
+                start: self.start + from,
+                len: to - from,
+                value: self.value,
+            }
+        }
+    }
+
+    fn run(start: usize, len: usize, value: u32) -> Run {
+        Run { start, len, value }
+    }
+
+    impl ZeroRun for Run {
+        fn zero_run(start: usize, end: usize) -> Self {
+            run(start, end - start, 0)
+        }
+    }
+
+    #[test]
+    fn fill_gaps_to() {
+        let mut a: RleVec<[Run; 4]> = RleVec::new();
+        a.push(run(2, 2, 1));
+        a.push(run(6, 1, 2));
+        // leading gap
+        assert_eq!(a.get_or_zero(0), run(0, 1, 0));
+        assert_eq!(a.get_or_zero(1), run(1, 1, 0));
+        assert_eq!(a.get_or_zero(3), run(3, 1, 1));
+        // interior gap
+        assert_eq!(a.get_or_zero(4), run(4, 1, 0));
+        // trailing gap
+        assert_eq!(a.get_or_zero(7), run(7, 1, 0));
+        assert_eq!(a.get_or_zero(100), run(100, 1, 0));
+
+        // the argument is an absolute index, not a length from the first element
+        a.fill_gaps_to(8);
+        assert_eq!(&**a.vec(), &[run(2, 2, 1), run(6, 1, 2), run(7, 1, 0)]);
+        assert_eq!(a.end(), 8);
+        // a filled atom reads the same as an unfilled one
+        assert_eq!(a.get_or_zero(7), run(7, 1, 0));
+        assert_eq!(a.get_or_zero(8), run(8, 1, 0));
+        a.push(run(8, 2, 3));
+        assert_eq!(a.get_or_zero(9), run(9, 1, 3));
+
+        // merge with the trailing zero run
+        a.fill_gaps_to(12);
+        a.fill_gaps_to(14);
+        assert_eq!(a.merged_len(), 5);
+        assert_eq!(a.last().unwrap(), &run(10, 4, 0));
+        a.fill_gaps_to(5);
+        assert_eq!(a.end(), 14);
+        assert_eq!(a.merged_len(), 5);
+
+        let mut b: RleVec<[Run; 4]> = RleVec::new();
+        assert_eq!(b.get_or_zero(0), run(0, 1, 0));
+        b.fill_gaps_to(3);
+        assert_eq!(&**b.vec(), &[run(0, 3, 0)]);
+    }
+
+    #[test]
+    fn iter_from() {
+        let mut a: RleVec<[Range<usize>; 4]> = RleVec::new();
+        assert_eq!(a.iter_from(0).count(), 0);
+        a.push(0..5);
+        a.push(10..13);
+        a.push(13..15);
+        assert!(a.iter_from(0).eq(a.vec().iter()));
+        assert_eq!(a.iter_from(3).collect::<Vec<_>>(), vec![&(0..5), &(10..15)]);
+        assert_eq!(a.iter_from(5).collect::<Vec<_>>(), vec![&(10..15)]);
+        assert_eq!(a.iter_from(14).collect::<Vec<_>>(), vec![&(10..15)]);
+        assert_eq!(a.iter_from(15).count(), 0);
+    }
 }
@@ -92,6 +92,20 @@ impl<T: Mergable<Cfg> + HasLength, Cfg> RleVecWithIndex<T, Cfg> {
         })
     }
 
+    /// Iterate over the merged elements starting from the one that contains `atom_index`.
+    ///
+    /// If `atom_index` lands inside a merged element, the first yielded item is the
+    /// **whole** containing element, not a truncated one. The caller should trim it
+    /// when needed. If `atom_index >= self.atom_len()`, the iterator is empty.
+    pub fn iter_from(&self, atom_index: usize) -> std::slice::Iter<'_, T> {
+        if atom_index >= self.atom_len {
+            return self.vec[self.vec.len()..].iter();
+        }
+
+        let merged_index = self.get(atom_index).unwrap().merged_index;
+        self.vec[merged_index..].iter()
+    }
+
     /// get a slice from `from` to `to` with atom indexes
     pub fn slice_iter(&self, from: usize, to: usize) -> SliceIterator<'_, T> {
         if from == to || self.merged_len() == 0 {
@@ -389,5 +403,18 @@ mod test {
             assert_eq!(second.start, 0);
             assert_eq!(second.end, 4);
         }
+
+        #[test]
+        fn iter_from() {
+            let mut vec: RleVecWithIndex<String> = RleVecWithIndex::new();
+            vec.push("1234".to_string());
+            vec.push("5678".to_string());
+            vec.push("12345678".to_string());
+            assert!(vec.iter_from(0).eq(vec.vec().iter()));
+            assert!(vec.iter_from(7).eq(vec.vec().iter()));
+            assert_eq!(vec.iter_from(8).collect::<Vec<_>>(), vec!["12345678"]);
+            assert_eq!(vec.iter_from(15).collect::<Vec<_>>(), vec!["12345678"]);
+            assert_eq!(vec.iter_from(16).count(), 0);
+        }
     }
 }